@@ -8,7 +8,12 @@ extern crate alloc;
 
 use core::cell::{Cell, RefCell};
 #[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::mem::ManuallyDrop;
 use core::ops::Deref;
+#[cfg(feature = "alloc")]
+use core::ops::DerefMut;
 use core::ptr::{self, NonNull};
 #[cfg(feature = "std")]
 use std::ffi::{CStr, CString};
@@ -34,6 +39,18 @@ macro_rules! asptr_wrapper {
     };
 }
 
+macro_rules! asmutptr_wrapper {
+    ($name:ident) => {
+        impl<T> AsMutPtr for $name<T> {
+            type Raw = T;
+            #[inline]
+            fn as_mut_ptr(&mut self) -> *mut T {
+                $name::as_ptr(self)
+            }
+        }
+    };
+}
+
 #[cfg(feature = "alloc")]
 macro_rules! owned_ptr_wrapper {
     ($name:ident) => {
@@ -51,6 +68,25 @@ macro_rules! owned_ptr_wrapper {
     };
 }
 
+/// Round-trip a smart pointer over an unsized pointee (such as `[T]` or
+/// `str`) through a length-carrying fat pointer.
+#[cfg(feature = "alloc")]
+macro_rules! owned_unsized_ptr_wrapper {
+    ($name:ident, $raw:ty, $($gen:tt)*) => {
+        impl<$($gen)*> IntoRaw for $name<$raw> {
+            type Raw = $raw;
+            fn into_raw(self) -> *mut $raw {
+                $name::into_raw(self) as *mut $raw
+            }
+        }
+        impl<$($gen)*> FromRaw<$raw> for $name<$raw> {
+            unsafe fn from_raw(raw: *mut $raw) -> $name<$raw> {
+                $name::from_raw(raw)
+            }
+        }
+    };
+}
+
 /// Trait for types that implement `as_ptr`.
 ///
 /// This is implemented by types which can be converted
@@ -180,6 +216,116 @@ asptr_wrapper!(Rc);
 #[cfg(feature = "alloc")]
 asptr_wrapper!(Arc);
 
+/// Trait for types that implement `as_mut_ptr`.
+///
+/// This is the mutable counterpart to `AsPtr`: it is implemented by types
+/// which can be converted to a mutable pointer from a mutable reference.
+///
+/// # Example
+/// ```
+/// use ptrplus::AsMutPtr;
+///
+/// let mut x: u32 = 5;
+/// let mut r: &mut u32 = &mut x;
+/// let y: *mut u32 = r.as_mut_ptr();
+/// unsafe {
+///     *y = 6;
+/// }
+/// assert_eq!(x, 6);
+/// ```
+///
+/// ```
+/// use ptrplus::AsMutPtr;
+///
+/// let mut x = 5;
+/// let mut o1: Option<&mut u32> = None;
+/// let mut o2: Option<&mut u32> = Some(&mut x);
+///
+/// assert!(o1.as_mut_ptr().is_null());
+/// assert!(!o2.as_mut_ptr().is_null());
+/// unsafe {
+///     *o2.as_mut_ptr() = 6;
+/// }
+/// assert_eq!(x, 6);
+/// ```
+pub trait AsMutPtr {
+    /// The type pointed to
+    ///
+    /// `as_mut_ptr` will return a pointer to this type
+    type Raw;
+
+    /// Returns a mutable raw pointer to the contained content
+    ///
+    /// The caller must ensure `self` outlives the pointer
+    /// that is returned, or else it will end up pointing
+    /// to garbage.
+    ///
+    /// Mutating `self` may also invalidate this pointer,
+    /// depending on the implementation.
+    fn as_mut_ptr(&mut self) -> *mut Self::Raw;
+}
+
+impl<T> AsMutPtr for [T] {
+    type Raw = T;
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        <[T]>::as_mut_ptr(self)
+    }
+}
+
+impl<T> AsMutPtr for &mut T
+where
+    T: Sized,
+{
+    type Raw = T;
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        *self as *mut T
+    }
+}
+
+impl<T> AsMutPtr for NonNull<T> {
+    type Raw = T;
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        NonNull::as_ptr(*self)
+    }
+}
+
+impl<T> AsMutPtr for *mut T {
+    type Raw = T;
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        *self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsMutPtr for Box<T> {
+    type Raw = T;
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.deref_mut().as_mut_ptr()
+    }
+}
+
+impl<T> AsMutPtr for Option<T>
+where
+    T: AsMutPtr,
+{
+    type Raw = T::Raw;
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T::Raw {
+        match self {
+            Some(ref mut v) => v.as_mut_ptr(),
+            None => ptr::null_mut(),
+        }
+    }
+}
+
+asmutptr_wrapper!(Cell);
+asmutptr_wrapper!(RefCell);
+
 /// Trait for types that implement `into_raw`
 ///
 /// This is implemented by types that can be converted
@@ -218,11 +364,26 @@ asptr_wrapper!(Arc);
 ///     assert!(!o2.is_none());
 /// }
 /// ```
+///
+/// Unsized pointees keep their length metadata when round-tripped:
+/// ```
+/// use ptrplus::{FromRaw, IntoRaw};
+///
+/// let x: Box<[u32]> = vec![1, 2, 3].into_boxed_slice();
+/// let y: *mut [u32] = x.into_raw();
+/// unsafe {
+///     assert_eq!(y.len(), 3);
+///     let z: Box<[u32]> = FromRaw::from_raw(y);
+///     assert_eq!(&*z, &[1, 2, 3]);
+/// }
+/// ```
 pub trait IntoRaw {
     /// The type pointed to
     ///
-    /// `into_raw` returns a mutable pointer to this type
-    type Raw;
+    /// `into_raw` returns a mutable pointer to this type. It may be
+    /// unsized (for example `[T]` or `str`), in which case the returned
+    /// pointer carries the appropriate metadata.
+    type Raw: ?Sized;
 
     /// Consumes `self` returning the wrapped raw pointer.
     ///
@@ -263,6 +424,7 @@ impl<T> IntoRaw for NonNull<T> {
 impl<T> IntoRaw for Option<T>
 where
     T: IntoRaw,
+    T::Raw: Sized,
 {
     type Raw = T::Raw;
     #[inline]
@@ -287,7 +449,7 @@ where
 ///
 /// ```
 ///
-pub trait FromRaw<T> {
+pub trait FromRaw<T: ?Sized> {
     /// Create `Self` from a raw pointer
     ///
     /// After calling this method the raw pointer
@@ -354,6 +516,7 @@ impl<T> FromRaw<T> for NonNull<T> {
 /// to convert into the inner pointer type.
 impl<T, U> FromRaw<U> for Option<T>
 where
+    U: ?Sized,
     T: FromRaw<U>,
 {
     unsafe fn from_raw(raw: *mut U) -> Option<T> {
@@ -371,3 +534,303 @@ owned_ptr_wrapper!(Box);
 owned_ptr_wrapper!(Rc);
 #[cfg(feature = "alloc")]
 owned_ptr_wrapper!(Arc);
+
+// Fat-pointer variants: these preserve the length metadata so that
+// length-carrying allocations can be handed to and reclaimed from FFI
+// without manually splitting the pointer and length.
+#[cfg(feature = "alloc")]
+owned_unsized_ptr_wrapper!(Box, [T], T);
+#[cfg(feature = "alloc")]
+owned_unsized_ptr_wrapper!(Rc, [T], T);
+#[cfg(feature = "alloc")]
+owned_unsized_ptr_wrapper!(Arc, [T], T);
+#[cfg(feature = "alloc")]
+owned_unsized_ptr_wrapper!(Box, str,);
+#[cfg(feature = "alloc")]
+owned_unsized_ptr_wrapper!(Rc, str,);
+#[cfg(feature = "alloc")]
+owned_unsized_ptr_wrapper!(Arc, str,);
+
+/// Trait for borrowing the value behind a raw pointer without reclaiming it.
+///
+/// This is the non-consuming counterpart to `FromRaw`. A common FFI pattern
+/// is to `into_raw` a `Box`, `Rc`, or `Arc`, hand the pointer to C, and later
+/// access the managed value through that pointer *without* taking ownership
+/// back or running the destructor. `borrow_raw` produces such a temporary,
+/// non-owning view.
+///
+/// # Example
+/// ```
+/// use ptrplus::{BorrowRaw, IntoRaw, FromRaw};
+/// use std::rc::Rc;
+///
+/// let p: *mut u32 = Rc::new(5u32).into_raw();
+/// unsafe {
+///     // Look at the value without touching the refcount.
+///     let borrowed = <Rc<u32>>::borrow_raw(p);
+///     assert_eq!(*borrowed, 5);
+///     drop(borrowed);
+///     // The allocation is still alive and can be reclaimed.
+///     let owned: Rc<u32> = FromRaw::from_raw(p);
+///     assert_eq!(*owned, 5);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub trait BorrowRaw {
+    /// The type pointed to by the raw pointer being borrowed.
+    type Raw: ?Sized;
+
+    /// The non-owning view handed out for the lifetime `'a`.
+    type Borrowed<'a>
+    where
+        Self: 'a;
+
+    /// Borrow the value behind `ptr` without taking ownership of it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been produced by a previous call to `into_raw` on the
+    /// corresponding type and must point to a live allocation for the whole
+    /// of `'a`. The returned value is a *borrow*: it must never be dropped as
+    /// an owning handle (doing so would free the allocation or decrement a
+    /// refcount it does not own), and the caller must keep the underlying
+    /// allocation alive for `'a` and not reclaim it via `from_raw` while the
+    /// borrow is live.
+    unsafe fn borrow_raw<'a>(ptr: *mut Self::Raw) -> Self::Borrowed<'a>;
+}
+
+/// A non-owning borrow of a smart pointer obtained through [`BorrowRaw`].
+///
+/// It derefs to the pointee but wraps the handle in `ManuallyDrop`, so
+/// dropping a `Borrowed` never runs the smart pointer's destructor or touches
+/// its reference count.
+#[cfg(feature = "alloc")]
+pub struct Borrowed<'a, P> {
+    handle: ManuallyDrop<P>,
+    _marker: PhantomData<&'a P>,
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Deref> Deref for Borrowed<'_, P> {
+    type Target = P::Target;
+    #[inline]
+    fn deref(&self) -> &P::Target {
+        &self.handle
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BorrowRaw for Box<T> {
+    type Raw = T;
+    type Borrowed<'a>
+        = &'a T
+    where
+        Self: 'a;
+    #[inline]
+    unsafe fn borrow_raw<'a>(ptr: *mut T) -> &'a T {
+        &*ptr
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! borrow_raw_wrapper {
+    ($name:ident) => {
+        impl<T: ?Sized> BorrowRaw for $name<T> {
+            type Raw = T;
+            type Borrowed<'a>
+                = Borrowed<'a, $name<T>>
+            where
+                Self: 'a;
+            #[inline]
+            unsafe fn borrow_raw<'a>(ptr: *mut T) -> Borrowed<'a, $name<T>> {
+                Borrowed {
+                    handle: ManuallyDrop::new($name::from_raw(ptr as *const T)),
+                    _marker: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+borrow_raw_wrapper!(Rc);
+#[cfg(feature = "alloc")]
+borrow_raw_wrapper!(Arc);
+
+/// A refcount-free shared owned pointer.
+///
+/// `UnsafeRef<T>` behaves like `Rc`/`Arc` for sharing &mdash; [`Clone`] just
+/// copies the pointer and [`Deref`] hands out a `&T` &mdash; but it keeps no
+/// reference count. This makes it a zero-overhead shared handle for intrusive
+/// data structures, at the cost of manual memory management: exactly one owner
+/// must eventually reclaim the allocation with [`into_box`](UnsafeRef::into_box).
+///
+/// # Safety
+///
+/// While any `UnsafeRef` to a value exists, the pointee must not be moved,
+/// dropped, or mutably aliased. Exactly one owner must call
+/// [`into_box`](UnsafeRef::into_box) to free the allocation, and no other
+/// `UnsafeRef` to it may be used afterwards.
+///
+/// # Example
+/// ```
+/// use ptrplus::UnsafeRef;
+///
+/// let a = UnsafeRef::from_box(Box::new(5u32));
+/// let b = a.clone();
+/// assert_eq!(*a, 5);
+/// assert_eq!(*b, 5);
+/// // `a` and `b` alias the same allocation; reclaim it exactly once.
+/// let boxed = unsafe { a.into_box() };
+/// assert_eq!(*boxed, 5);
+/// ```
+pub struct UnsafeRef<T: ?Sized> {
+    ptr: NonNull<T>,
+}
+
+impl<T: ?Sized> UnsafeRef<T> {
+    /// Create an `UnsafeRef` from an owned `Box`, taking over its allocation.
+    ///
+    /// The returned handle is responsible for the allocation; it must
+    /// eventually be reclaimed with [`into_box`](UnsafeRef::into_box).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn from_box(value: Box<T>) -> Self {
+        // `Box::into_raw` never returns a null pointer.
+        UnsafeRef {
+            ptr: unsafe { NonNull::new_unchecked(Box::into_raw(value)) },
+        }
+    }
+
+    /// Reclaim the allocation as a `Box`, allowing it to be dropped.
+    ///
+    /// # Safety
+    ///
+    /// The pointee must have originally been allocated as a `Box` (for
+    /// example via [`from_box`](UnsafeRef::from_box)). This consumes the
+    /// single owning reclamation: no other `UnsafeRef` aliasing the same
+    /// allocation may be used afterwards.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub unsafe fn into_box(self) -> Box<T> {
+        Box::from_raw(self.ptr.as_ptr())
+    }
+}
+
+impl<T: ?Sized> Clone for UnsafeRef<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        UnsafeRef { ptr: self.ptr }
+    }
+}
+
+impl<T: ?Sized> Deref for UnsafeRef<T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        // Safety: by the type's contract the pointee outlives every handle.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T> AsPtr for UnsafeRef<T> {
+    type Raw = T;
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+}
+
+impl<T: ?Sized> IntoRaw for UnsafeRef<T> {
+    type Raw = T;
+    #[inline]
+    fn into_raw(self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+}
+
+/// ## Safety
+/// The input pointer must be non-null and valid for the lifetime of every
+/// handle derived from it. See [`UnsafeRef`] for the full contract.
+impl<T: ?Sized> FromRaw<T> for UnsafeRef<T> {
+    #[inline]
+    unsafe fn from_raw(raw: *mut T) -> UnsafeRef<T> {
+        UnsafeRef {
+            ptr: NonNull::new_unchecked(raw),
+        }
+    }
+}
+
+/// Trait for reinterpreting a pointer's element type while preserving the
+/// kind of pointer.
+///
+/// This casts the pointee type without reallocating: a `Box<T>` becomes a
+/// `Box<U>`, an `Rc<T>` becomes an `Rc<U>` (keeping the same refcount
+/// allocation), and so on. It is useful for bridging between a
+/// `#[repr(transparent)]` newtype and its inner type across an FFI boundary.
+///
+/// The `Box`, `Rc`, `Arc` and `NonNull` implementations round-trip through the
+/// crate's existing [`IntoRaw`]/[`FromRaw`] conversions, so the underlying
+/// allocation (and, for `Rc`/`Arc`, its reference count) is preserved. The
+/// `*mut T` and `*const T` implementations are plain pointer casts.
+///
+/// # Example
+/// ```
+/// use ptrplus::CastElement;
+///
+/// #[repr(transparent)]
+/// struct Wrapper(u32);
+///
+/// let b: Box<Wrapper> = Box::new(Wrapper(5));
+/// let inner: Box<u32> = unsafe { b.cast_element() };
+/// assert_eq!(*inner, 5);
+/// ```
+pub trait CastElement<U>: Sized {
+    /// The resulting pointer type, with element type `U`.
+    type Output;
+
+    /// Reinterpret `self` as a pointer to `U`.
+    ///
+    /// # Safety
+    ///
+    /// `T` and `U` must have identical size and alignment, and the bit
+    /// pattern of the pointee must be a valid value of type `U`. Violating
+    /// this is undefined behavior.
+    unsafe fn cast_element(self) -> Self::Output;
+}
+
+macro_rules! cast_element_wrapper {
+    ($name:ident) => {
+        impl<T, U> CastElement<U> for $name<T> {
+            type Output = $name<U>;
+            #[inline]
+            unsafe fn cast_element(self) -> $name<U> {
+                <$name<U> as FromRaw<U>>::from_raw(IntoRaw::into_raw(self) as *mut U)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+cast_element_wrapper!(Box);
+#[cfg(feature = "alloc")]
+cast_element_wrapper!(Rc);
+#[cfg(feature = "alloc")]
+cast_element_wrapper!(Arc);
+cast_element_wrapper!(NonNull);
+
+impl<T, U> CastElement<U> for *mut T {
+    type Output = *mut U;
+    #[inline]
+    unsafe fn cast_element(self) -> *mut U {
+        self as *mut U
+    }
+}
+
+impl<T, U> CastElement<U> for *const T {
+    type Output = *const U;
+    #[inline]
+    unsafe fn cast_element(self) -> *const U {
+        self as *const U
+    }
+}